@@ -0,0 +1,53 @@
+use chrono::Local;
+use env_logger::Builder;
+use log::LevelFilter;
+use mdbook::{
+    errors::Error,
+    renderer::{RenderContext, Renderer},
+};
+
+use mdbook_blog::FeedRenderer;
+
+use std::{env, io, io::Write, process};
+
+/// Init env. logger
+///
+/// Adapated from mdBook's owm logger:
+/// https://github.com/rust-lang/mdBook/blob/efb671aaf241b7f93597ac70178989a332fe85e0/src/main.rs#LL97-L121C2
+fn init_logger() {
+    let mut builder = Builder::new();
+
+    builder.format(|formatter, record| {
+        writeln!(
+            formatter,
+            "{} [{}] ({}): {}",
+            Local::now().format("%Y-%m-%d %H:%M:%S"),
+            record.level(),
+            record.target(),
+            record.args()
+        )
+    });
+
+    if let Ok(var) = env::var("RUST_LOG") {
+        builder.parse_filters(&var);
+    } else {
+        builder.filter(None, LevelFilter::Info);
+    }
+
+    builder.init();
+}
+
+fn main() {
+    init_logger();
+
+    if let Err(e) = handle_rendering(FeedRenderer::new()) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+fn handle_rendering(renderer: impl Renderer) -> Result<(), Error> {
+    let ctx = RenderContext::from_json(io::stdin())?;
+
+    renderer.render(&ctx)
+}
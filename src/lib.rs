@@ -1,10 +1,15 @@
+use chrono::Local;
 use mdbook::{
     book::{Book, BookItem, Chapter},
     errors::{Error, Result},
     preprocess::{Preprocessor, PreprocessorContext},
+    renderer::{RenderContext, Renderer},
+    Config as MdBookConfig,
 };
+use pulldown_cmark::{Event, Parser, TagEnd};
 use serde::Deserialize;
 use std::{
+    collections::{BTreeMap, HashMap},
     io,
     path::{Path, PathBuf},
 };
@@ -12,6 +17,9 @@ use walkdir::WalkDir;
 
 type Date = chrono::naive::NaiveDate;
 
+/// HTML comment marking where a post's excerpt should be cut off.
+const MORE_MARKER: &str = "<!-- more -->";
+
 #[derive(Debug, Deserialize)]
 struct PostsDirectory(String);
 
@@ -45,13 +53,134 @@ impl Default for SortBy {
     }
 }
 
-#[derive(Debug, Default, Deserialize)]
+#[derive(Debug, Deserialize)]
+struct TagsChapterName(String);
+
+impl Default for TagsChapterName {
+    fn default() -> Self {
+        Self("Tags".to_string())
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_feed_count() -> usize {
+    10
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum FeedFormat {
+    Atom,
+    Rss,
+}
+
+impl Default for FeedFormat {
+    fn default() -> Self {
+        Self::Atom
+    }
+}
+
+#[derive(Debug, Deserialize)]
 #[serde(default, rename_all = "kebab-case")]
 struct Config {
     directory: PostsDirectory,
     future: bool,
     chapter_name: ChapterName,
     sort_by: SortBy,
+    drafts: bool,
+    tags_chapter_name: TagsChapterName,
+    #[serde(default = "default_true")]
+    generate_tag_pages: bool,
+    /// The language for which posts live directly under `directory`,
+    /// without a language sub-directory. `None` disables localization.
+    language: Option<String>,
+    generate_feed: bool,
+    feed_format: FeedFormat,
+    #[serde(default = "default_feed_count")]
+    feed_count: usize,
+    /// The site's absolute base URL, needed to build absolute feed links.
+    site_url: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            directory: PostsDirectory::default(),
+            future: false,
+            chapter_name: ChapterName::default(),
+            sort_by: SortBy::default(),
+            drafts: false,
+            tags_chapter_name: TagsChapterName::default(),
+            generate_tag_pages: true,
+            language: None,
+            generate_feed: false,
+            feed_format: FeedFormat::default(),
+            feed_count: default_feed_count(),
+            site_url: None,
+        }
+    }
+}
+
+/// Metadata that a post may declare in a YAML (`---`) or TOML (`+++`) front
+/// matter block at the top of its file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct FrontMatter {
+    title: Option<String>,
+    date: Option<Date>,
+    authors: Vec<String>,
+    tags: Vec<String>,
+    draft: bool,
+}
+
+/// Splits `content` into a front matter block and the remaining body, if
+/// `content` starts with a `delimiter`-fenced block (e.g. `---` or `+++`).
+///
+/// The closing fence must sit alone on its own line: a bare occurrence of
+/// `delimiter` elsewhere (e.g. inside a sentence, or a markdown horizontal
+/// rule made of more dashes) does not close the block.
+fn split_front_matter<'a>(content: &'a str, delimiter: &str) -> Option<(&'a str, &'a str)> {
+    let rest = content
+        .strip_prefix(delimiter)?
+        .trim_start_matches(['\r', '\n']);
+
+    let closing = format!("\n{delimiter}");
+    let mut search_from = 0;
+
+    loop {
+        let found = rest[search_from..].find(&closing)?;
+        let fence_start = search_from + found;
+        let after_fence = &rest[fence_start + closing.len()..];
+        let line_end = after_fence.find('\n').unwrap_or(after_fence.len());
+
+        if after_fence[..line_end].trim().is_empty() {
+            let block = &rest[..fence_start];
+            let body = rest[fence_start + closing.len() + line_end..].trim_start_matches(['\r', '\n']);
+            return Some((block, body));
+        }
+
+        search_from = fence_start + closing.len();
+    }
+}
+
+/// Parses an optional front matter block out of a post's raw `content`,
+/// returning the deserialized [`FrontMatter`] (or its default, if none is
+/// present) alongside the remaining markdown body.
+fn parse_front_matter(content: &str) -> Result<(FrontMatter, String)> {
+    if let Some((yaml, body)) = split_front_matter(content, "---") {
+        let front_matter = serde_yaml::from_str(yaml)?;
+        return Ok((front_matter, body.to_string()));
+    }
+
+    if let Some((toml, body)) = split_front_matter(content, "+++") {
+        let front_matter: FrontMatter = toml::from_str(toml)?;
+        return Ok((front_matter, body.to_string()));
+    }
+
+    Ok((FrontMatter::default(), content.to_string()))
 }
 
 #[derive(Debug)]
@@ -60,44 +189,38 @@ struct Post {
     date: Date,
     name: String,
     parent_name: String,
+    content: String,
+    front_matter: FrontMatter,
 }
 
 impl Post {
     #[inline]
-    fn new(path: PathBuf, date: Date, name: String, parent_name: String) -> Self {
+    fn new(
+        path: PathBuf,
+        date: Date,
+        name: String,
+        parent_name: String,
+        content: String,
+        front_matter: FrontMatter,
+    ) -> Self {
         return Self {
             path,
             date,
             name,
             parent_name,
-        };
-    }
-}
-
-impl TryFrom<PathBuf> for Post {
-    type Error = Error;
-    fn try_from(path: PathBuf) -> Result<Self> {
-        let content = std::fs::read_to_string(&path)?;
-        //eprintln!("content: {}", content);
-        //let content = String::new();
-        Ok(Chapter::new(
-            "test",
             content,
-            post.path,
-            vec![post.parent_name],
-        ))
+            front_matter,
+        };
     }
 }
 
 impl TryFrom<Post> for Chapter {
     type Error = io::Error;
     fn try_from(post: Post) -> io::Result<Self> {
-        let content = std::fs::read_to_string(&post.path)?;
-        //eprintln!("content: {}", content);
-        //let content = String::new();
+        let name = post_title(&post);
         Ok(Chapter::new(
-            "test",
-            content,
+            name,
+            post.content,
             post.path,
             vec![post.parent_name],
         ))
@@ -137,6 +260,51 @@ fn extract_date_from_filename<P: AsRef<Path>>(path: P) -> Result<Date> {
     Ok(date)
 }
 
+/// Returns a post's display title: its front matter `title` if set,
+/// otherwise its slug with separators turned into spaces and each word
+/// capitalized.
+fn post_title(post: &Post) -> String {
+    post.front_matter
+        .title
+        .clone()
+        .unwrap_or_else(|| de_slugify(&post.name))
+}
+
+/// Turns a slug such as `my-super-post` into a human-readable title such as
+/// `My Super Post`.
+fn de_slugify(slug: &str) -> String {
+    slug.split(['-', '_'])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the slug portion of a post's filename, i.e. everything after the
+/// `YYYY-MM-DD-` prefix and before the `.md` extension.
+fn extract_slug_from_filename<P: AsRef<Path>>(path: P) -> String {
+    let stem = path.as_ref().file_stem().unwrap().to_string_lossy();
+
+    let mut count = 0;
+
+    for (i, c) in stem.char_indices() {
+        if c == '-' {
+            count += 1;
+            if count == 3 {
+                return stem[i + 1..].to_string();
+            }
+        }
+    }
+
+    stem.to_string()
+}
+
 /// Returns a vector of [`Post`],
 /// from a [`walkdir::WalkDir`].
 ///
@@ -144,7 +312,13 @@ fn extract_date_from_filename<P: AsRef<Path>>(path: P) -> Result<Date> {
 /// - a file;
 /// - its name ends with '.md';
 /// - and starts is formatted like YYYY-MM-DD-my-super-post.
-fn collect_posts(walkdir: WalkDir, parent_name: String) -> Vec<Post> {
+///
+/// A filename without the date prefix is also accepted when the post's front
+/// matter sets `date`, which takes precedence over the filename's date.
+///
+/// Posts carrying `draft: true` in their front matter are skipped unless
+/// `allow_drafts` is set.
+fn collect_posts(walkdir: WalkDir, parent_name: String, allow_drafts: bool) -> Vec<Post> {
     walkdir
         .into_iter()
         .filter_map(|result| {
@@ -152,11 +326,51 @@ fn collect_posts(walkdir: WalkDir, parent_name: String) -> Vec<Post> {
                 Ok(dir_entry) => {
                     let path_buf = dir_entry.into_path();
                     if path_buf.is_file() && path_buf.extension().map_or(false, |ext| ext == "md") {
-                        match extract_date_from_filename(&path_buf) {
-                            Ok(date) => Some(Post::new(path_buf, date, parent_name.clone())),
+                        let content = match std::fs::read_to_string(&path_buf) {
+                            Ok(content) => content,
+                            Err(e) => {
+                                log::error!("An error occured while reading {path_buf:?}: {e}");
+                                return None;
+                            },
+                        };
+
+                        let (front_matter, body) = match parse_front_matter(&content) {
+                            Ok(parsed) => parsed,
                             Err(e) => {
                                 log::error!(
-                                    "An error occured while extracting date from {path_buf:?}: {e}",
+                                    "An error occured while parsing front matter of \
+                                     {path_buf:?}: {e}",
+                                );
+                                (FrontMatter::default(), content)
+                            },
+                        };
+
+                        if front_matter.draft && !allow_drafts {
+                            log::info!("Skipping draft post {path_buf:?}");
+                            return None;
+                        }
+
+                        let date = front_matter
+                            .date
+                            .or_else(|| extract_date_from_filename(&path_buf).ok());
+
+                        match date {
+                            Some(date) => {
+                                let name = extract_slug_from_filename(&path_buf);
+                                Some(Post::new(
+                                    path_buf,
+                                    date,
+                                    name,
+                                    parent_name.clone(),
+                                    body,
+                                    front_matter,
+                                ))
+                            },
+                            None => {
+                                log::error!(
+                                    "Could not determine a date for {path_buf:?}: no front \
+                                     matter `date` and the filename isn't formatted like \
+                                     YYYY-MM-DD-my-super-post",
                                 );
                                 None
                             },
@@ -174,8 +388,85 @@ fn collect_posts(walkdir: WalkDir, parent_name: String) -> Vec<Post> {
         .collect()
 }
 
-fn get_config(ctx: &PreprocessorContext) -> Config {
-    ctx.config
+/// Walks both `<posts_dir>/<language>` (the localized posts) and
+/// `<posts_dir>` (the fallback, default-language posts), preferring a
+/// localized post over its fallback when both exist for the same date+slug.
+/// Slugs without a localized translation are logged as a warning.
+fn collect_localized_posts(
+    posts_dir: &Path,
+    language: &str,
+    parent_name: String,
+    allow_drafts: bool,
+) -> Vec<Post> {
+    let localized_dir = posts_dir.join(language);
+
+    let mut localized: HashMap<(Date, String), Post> = collect_posts(
+        WalkDir::new(&localized_dir).max_depth(1),
+        parent_name.clone(),
+        allow_drafts,
+    )
+    .into_iter()
+    .map(|post| ((post.date, post.name.clone()), post))
+    .collect();
+
+    let fallback = collect_posts(WalkDir::new(posts_dir).max_depth(1), parent_name, allow_drafts);
+
+    let mut untranslated = Vec::new();
+    let mut posts = Vec::with_capacity(fallback.len());
+
+    for post in fallback {
+        let key = (post.date, post.name.clone());
+        match localized.remove(&key) {
+            Some(localized_post) => posts.push(localized_post),
+            None => {
+                untranslated.push(format!("{}-{}", key.0, key.1));
+                posts.push(post);
+            },
+        }
+    }
+
+    posts.extend(localized.into_values());
+
+    if !untranslated.is_empty() {
+        log::warn!(
+            "Missing {language} translations for: {}",
+            untranslated.join(", ")
+        );
+    }
+
+    posts
+}
+
+/// Drops posts dated after `today`, unless `future` is set, in which case
+/// they are kept as-is. Skipped posts are logged at info level.
+fn filter_future_posts(posts: Vec<Post>, future: bool, today: Date) -> Vec<Post> {
+    if future {
+        return posts;
+    }
+
+    posts
+        .into_iter()
+        .filter(|post| {
+            if post.date > today {
+                log::info!("Skipping future post {:?} dated {}", post.path, post.date);
+                false
+            } else {
+                true
+            }
+        })
+        .collect()
+}
+
+/// Prepends a small notice to `content` marking it as scheduled for a future
+/// `date`, for use when `future` posts are kept rather than dropped.
+fn inject_scheduled_notice(content: &str, date: Date) -> String {
+    format!(
+        "> **Scheduled:** this post is dated {date} and has not been published yet.\n\n{content}"
+    )
+}
+
+fn get_config(mdbook_config: &MdBookConfig) -> Config {
+    mdbook_config
         .get("preprocessor.blog")
         .map(|value| {
             match value.clone().try_into() {
@@ -191,54 +482,370 @@ fn get_config(ctx: &PreprocessorContext) -> Config {
         .unwrap_or_default()
 }
 
-impl Preprocessor for BlogPreprocessor {
-    fn name(&self) -> &str {
-        "blog"
+/// Collects every post under `root`/`<book.src>`/`<directory>`, resolves
+/// localization and future-dating, and sorts it according to the resolved
+/// [`Config`]. Shared by the preprocessor (which turns posts into chapters)
+/// and [`FeedRenderer`] (which only needs their metadata), so both stay in
+/// agreement on which posts exist and in what order.
+fn collect_sorted_posts(root: &Path, mdbook_config: &MdBookConfig) -> (Config, Vec<Post>) {
+    let src_dir = root.join(&mdbook_config.book.src);
+
+    let config: Config = get_config(mdbook_config);
+
+    let posts_dir = src_dir.join(&config.directory.0);
+
+    log::info!("{posts_dir:?}");
+
+    let active_language = mdbook_config.book.language.clone();
+
+    let posts = match (&config.language, &active_language) {
+        (Some(default_language), Some(active_language)) if default_language != active_language => {
+            collect_localized_posts(
+                &posts_dir,
+                active_language,
+                config.chapter_name.0.clone(),
+                config.drafts,
+            )
+        },
+        _ => collect_posts(
+            WalkDir::new(&posts_dir),
+            config.chapter_name.0.clone(),
+            config.drafts,
+        ),
+    };
+
+    let today = Local::now().date_naive();
+    let mut posts = filter_future_posts(posts, config.future, today);
+    sort_posts(&mut posts, &config.sort_by);
+
+    log::info!("Collected {posts:?} posts");
+
+    (config, posts)
+}
+
+/// Sorts `posts` in place according to `sort_by`. Ties always break by slug,
+/// so ordering stays deterministic.
+fn sort_posts(posts: &mut [Post], sort_by: &SortBy) {
+    match sort_by {
+        SortBy::Newest => {
+            posts.sort_by(|a, b| b.date.cmp(&a.date).then_with(|| a.name.cmp(&b.name)))
+        },
+        SortBy::Oldest => {
+            posts.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.name.cmp(&b.name)))
+        },
+        SortBy::NameAZ => posts.sort_by(|a, b| {
+            post_title(a).cmp(&post_title(b)).then_with(|| a.name.cmp(&b.name))
+        }),
+        SortBy::NameZA => posts.sort_by(|a, b| {
+            post_title(b).cmp(&post_title(a)).then_with(|| a.name.cmp(&b.name))
+        }),
     }
+}
 
-    fn run(&self, ctx: &PreprocessorContext, mut book: Book) -> Result<Book> {
-        let src_dir = ctx.root.join(&ctx.config.book.src);
+/// Extracts a short markdown excerpt out of a post's full markdown `content`.
+///
+/// If the content contains an explicit `<!-- more -->` marker, everything up
+/// to (and excluding) that marker is used. Otherwise, the first paragraph's
+/// worth of events is used instead.
+fn extract_excerpt(content: &str) -> String {
+    let parser = Parser::new(content);
+    let mut events = Vec::new();
+    let has_marker = content.lines().any(|line| line.trim() == MORE_MARKER);
+
+    for event in parser {
+        match &event {
+            Event::Html(html) if html.trim() == MORE_MARKER => break,
+            Event::End(TagEnd::Paragraph) if !has_marker => {
+                events.push(event);
+                break;
+            },
+            _ => {},
+        }
+        events.push(event);
+    }
 
-        let config: Config = get_config(ctx);
+    let mut excerpt = String::new();
+    pulldown_cmark_to_cmark::cmark(events.into_iter(), &mut excerpt)
+        .expect("this should not fail, as events come from a valid markdown parser");
+    excerpt
+}
 
-        let posts_dir = src_dir.join(&config.directory.0);
+/// Builds the markdown content of the posts index chapter: one entry per
+/// post, linking to its chapter and followed by its excerpt.
+fn build_index_content(chapters: &[Chapter]) -> String {
+    chapters
+        .iter()
+        .map(|chapter| {
+            let path = chapter
+                .path
+                .as_ref()
+                .map(|path| path.display().to_string())
+                .unwrap_or_default();
+            let excerpt = extract_excerpt(&chapter.content);
+            format!("- [{}]({})\n\n  {}\n", chapter.name, path, excerpt)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
-        log::info!("{posts_dir:?}");
-        //let mut sections = &book.sections;
+/// Turns an arbitrary tag name into a filesystem- and URL-safe slug.
+fn slugify(value: &str) -> String {
+    value
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect()
+}
 
-        /*
-        let mut posts_chapter = Chapter::new(config.chapter, "".
+/// Builds the "Tags" overview chapter and its sub-pages: one page per tag,
+/// each listing the posts carrying that tag, plus an overview page linking
+/// to every tag. `post_chapters` and `post_tags` must have the same length
+/// and be index-aligned.
+fn build_tags_overview(
+    post_chapters: &[Chapter],
+    post_tags: &[Vec<String>],
+    tags_chapter_name: &str,
+) -> Chapter {
+    let mut tags: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
 
-        let mut posts_chapter: &mut Chapter = book
-            .sections
+    for (index, post_tags) in post_tags.iter().enumerate() {
+        for tag in post_tags {
+            tags.entry(tag.as_str()).or_default().push(index);
+        }
+    }
+
+    let mut overview_lines = Vec::with_capacity(tags.len());
+    let mut tag_chapters = Vec::with_capacity(tags.len());
+
+    for (tag, indices) in tags {
+        let tag_path = PathBuf::from(tags_chapter_name).join(format!("{}.md", slugify(tag)));
+
+        let content = indices
             .iter()
-            .filter_map(|book_item| {
-                match book_item {
-                    BookItem::Chapter(chapter) => Some(chapter),
-                    _ => None,
-                }
+            .map(|&index| {
+                let chapter = &post_chapters[index];
+                let path = chapter
+                    .path
+                    .as_ref()
+                    .map(|path| path.display().to_string())
+                    .unwrap_or_default();
+                format!("- [{}]({})", chapter.name, path)
             })
-            .find(|chapter| chapter.name == config.chapter_name.0)
-            .ok_or(Error::msg(format!(
-                "Could not find chapter \"{}\": did you forget to include a draft chapter using \
-                 '- [{}]()' syntax in SUMMARY.md?",
-                config.chapter_name.0, config.chapter_name.0
-            )))?;
-        */
-
-        let walkdir = WalkDir::new(posts_dir);
-        let mut posts = collect_posts(walkdir, config.chapter_name.0.clone());
-
-        match config.sort_by {
-            SortBy::Newest => posts.sort_by(|a, b| a.date.cmp(&b.date)),
-            SortBy::Newest => posts.sort_by(|a, b| b.date.cmp(&a.date)),
-            SortBy::NameAZ => posts.sort_by(|a, b| a.name.cmp(&b.name)),
-            SortBy::NameZA => posts.sort_by(|a, b| b.name.cmp(&a.name)),
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        overview_lines.push(format!("- [{tag}]({})", tag_path.display()));
+
+        let tag_chapter = Chapter::new(tag, content, tag_path, vec![
+            tags_chapter_name.to_string(),
+        ]);
+        tag_chapters.push(tag_chapter);
+    }
+
+    let mut overview = Chapter::new(
+        tags_chapter_name,
+        overview_lines.join("\n"),
+        PathBuf::from(tags_chapter_name).join("index.md"),
+        vec![],
+    );
+    overview.sub_items = tag_chapters.into_iter().map(BookItem::Chapter).collect();
+    overview
+}
+
+/// A single entry in the generated RSS/Atom feed.
+#[derive(Debug)]
+struct FeedEntry {
+    title: String,
+    link: String,
+    date: Date,
+    excerpt: String,
+}
+
+/// Picks the `count` most recently dated `posts` and turns them into feed
+/// entries, with absolute links built from `site_url` and paths made
+/// relative to `src_dir`.
+fn build_feed_entries(
+    posts: &[Post],
+    src_dir: &Path,
+    site_url: &str,
+    count: usize,
+) -> Vec<FeedEntry> {
+    let mut indices: Vec<usize> = (0..posts.len()).collect();
+    indices.sort_by(|&a, &b| posts[b].date.cmp(&posts[a].date));
+
+    indices
+        .into_iter()
+        .take(count)
+        .map(|index| {
+            let post = &posts[index];
+            let path = post
+                .path
+                .strip_prefix(src_dir)
+                .unwrap_or(&post.path)
+                .with_extension("html");
+            FeedEntry {
+                title: post_title(post),
+                link: format!("{}/{}", site_url.trim_end_matches('/'), path.display()),
+                date: post.date,
+                excerpt: extract_excerpt(&post.content),
+            }
+        })
+        .collect()
+}
+
+/// Escapes the handful of characters that are significant in XML text and
+/// attribute content.
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Renders `entries` as an Atom feed (RFC 4287) for `site_url`.
+fn render_atom_feed(entries: &[FeedEntry], site_url: &str) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    xml.push_str(&format!("  <title>{}</title>\n", escape_xml(site_url)));
+    xml.push_str(&format!(
+        "  <link href=\"{}\"/>\n",
+        escape_xml(site_url)
+    ));
+    xml.push_str(&format!("  <id>{}</id>\n", escape_xml(site_url)));
+    xml.push_str(&format!("  <updated>{}</updated>\n", Local::now().to_rfc3339()));
+
+    for entry in entries {
+        xml.push_str("  <entry>\n");
+        xml.push_str(&format!("    <title>{}</title>\n", escape_xml(&entry.title)));
+        xml.push_str(&format!(
+            "    <link href=\"{}\"/>\n",
+            escape_xml(&entry.link)
+        ));
+        xml.push_str(&format!("    <id>{}</id>\n", escape_xml(&entry.link)));
+        xml.push_str(&format!("    <updated>{}T00:00:00Z</updated>\n", entry.date));
+        xml.push_str(&format!(
+            "    <summary>{}</summary>\n",
+            escape_xml(&entry.excerpt)
+        ));
+        xml.push_str("  </entry>\n");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+/// Generates the blog's RSS/Atom feed as its own mdBook renderer.
+///
+/// This must not be done from [`BlogPreprocessor::run`]: preprocessors run
+/// before renderers, and the HTML renderer clears and repopulates its
+/// destination directory at the start of its own `render`, which would wipe
+/// out any feed file written earlier by a preprocessor. Declaring `blog-feed`
+/// as its own `[output.blog-feed]` renderer instead runs it during the
+/// render phase, after preprocessing is done.
+///
+/// The feed is written straight into the HTML renderer's own destination
+/// (`<build-dir>/html`), next to the pages it links to, rather than into
+/// this renderer's own `<build-dir>/blog-feed` - otherwise publishing
+/// `<build-dir>/html` as-is would never carry the feed along. For the write
+/// to land *after* the HTML renderer has cleared and repopulated that
+/// directory, `[output.blog-feed]` must be declared after `[output.html]`
+/// in `book.toml`, since mdbook runs renderers in declaration order.
+pub struct FeedRenderer;
+
+impl FeedRenderer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Renderer for FeedRenderer {
+    fn name(&self) -> &str {
+        "blog-feed"
+    }
+
+    fn render(&self, ctx: &RenderContext) -> Result<()> {
+        let (config, posts) = collect_sorted_posts(&ctx.root, &ctx.config);
+
+        if !config.generate_feed || posts.is_empty() {
+            return Ok(());
         }
 
-        log::info!("Collected {posts:?} posts");
+        let Some(site_url) = &config.site_url else {
+            log::error!(
+                "`generate-feed` is enabled but no `site-url` was configured: skipping feed \
+                 generation"
+            );
+            return Ok(());
+        };
+
+        let src_dir = ctx.root.join(&ctx.config.book.src);
+        let entries = build_feed_entries(&posts, &src_dir, site_url, config.feed_count);
+
+        let (file_name, xml) = match config.feed_format {
+            FeedFormat::Atom => ("atom.xml", render_atom_feed(&entries, site_url)),
+            FeedFormat::Rss => ("rss.xml", render_rss_feed(&entries, site_url)),
+        };
+
+        let html_dir = ctx.root.join(&ctx.config.build.build_dir).join("html");
+        std::fs::create_dir_all(&html_dir)?;
+        std::fs::write(html_dir.join(file_name), xml)?;
+
+        Ok(())
+    }
+}
+
+/// Renders `entries` as an RSS 2.0 feed for `site_url`.
+fn render_rss_feed(entries: &[FeedEntry], site_url: &str) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<rss version=\"2.0\">\n  <channel>\n");
+    xml.push_str(&format!("    <title>{}</title>\n", escape_xml(site_url)));
+    xml.push_str(&format!("    <link>{}</link>\n", escape_xml(site_url)));
+
+    for entry in entries {
+        xml.push_str("    <item>\n");
+        xml.push_str(&format!(
+            "      <title>{}</title>\n",
+            escape_xml(&entry.title)
+        ));
+        xml.push_str(&format!("      <link>{}</link>\n", escape_xml(&entry.link)));
+        xml.push_str(&format!("      <guid>{}</guid>\n", escape_xml(&entry.link)));
+        xml.push_str(&format!(
+            "      <pubDate>{}</pubDate>\n",
+            entry.date.format("%a, %d %b %Y 00:00:00 GMT")
+        ));
+        xml.push_str(&format!(
+            "      <description>{}</description>\n",
+            escape_xml(&entry.excerpt)
+        ));
+        xml.push_str("    </item>\n");
+    }
+
+    xml.push_str("  </channel>\n</rss>\n");
+    xml
+}
+
+impl Preprocessor for BlogPreprocessor {
+    fn name(&self) -> &str {
+        "blog"
+    }
+
+    fn run(&self, ctx: &PreprocessorContext, mut book: Book) -> Result<Book> {
+        let src_dir = ctx.root.join(&ctx.config.book.src);
+
+        let (config, posts) = collect_sorted_posts(&ctx.root, &ctx.config);
+
+        let today = Local::now().date_naive();
+
+        let mut post_chapters = Vec::with_capacity(posts.len());
+        let mut post_tags = Vec::with_capacity(posts.len());
+        let mut post_dates = Vec::with_capacity(posts.len());
 
         for post in posts.into_iter() {
+            let tags = post.front_matter.tags.clone();
+            let date = post.date;
             let mut chapter: Chapter = post.try_into()?;
             chapter.parent_names = vec![config.chapter_name.0.clone()];
             chapter.source_path = Some(
@@ -250,17 +857,68 @@ impl Preprocessor for BlogPreprocessor {
                     .into(),
             );
             chapter.path = chapter.source_path.clone();
-            //posts_chapter.sub_items.push(chapter.clone().into());
-            log::info!("chapter: {:?}", chapter);
-            book.push_item(chapter);
+            post_chapters.push(chapter);
+            post_tags.push(tags);
+            post_dates.push(date);
+        }
+
+        let index_content = (!post_chapters.is_empty()).then(|| build_index_content(&post_chapters));
+
+        if config.future {
+            for (chapter, date) in post_chapters.iter_mut().zip(post_dates.iter()) {
+                if *date > today {
+                    chapter.content = inject_scheduled_notice(&chapter.content, *date);
+                }
+            }
         }
 
-        for item in book.iter() {
-            if let BookItem::Chapter(ref ch) = *item {
-                log::info!("{:?}", ch);
+        if config.generate_tag_pages {
+            for (chapter, tags) in post_chapters.iter_mut().zip(post_tags.iter()) {
+                if !tags.is_empty() {
+                    chapter.content = format!("*Tags: {}*\n\n{}", tags.join(", "), chapter.content);
+                }
             }
         }
 
+        let tags_overview = (config.generate_tag_pages && !post_chapters.is_empty())
+            .then(|| build_tags_overview(&post_chapters, &post_tags, &config.tags_chapter_name.0));
+
+        if let Some(index_content) = index_content {
+            let existing_index = book.sections.iter_mut().find_map(|item| match item {
+                BookItem::Chapter(chapter) if chapter.name == config.chapter_name.0 => {
+                    Some(chapter)
+                },
+                _ => None,
+            });
+
+            match existing_index {
+                Some(index_chapter) => {
+                    if !index_chapter.content.trim().is_empty() {
+                        index_chapter.content.push_str("\n\n");
+                    }
+                    index_chapter.content.push_str(&index_content);
+                    index_chapter
+                        .sub_items
+                        .extend(post_chapters.into_iter().map(BookItem::Chapter));
+                },
+                None => {
+                    let mut index_chapter = Chapter::new(
+                        &config.chapter_name.0,
+                        index_content,
+                        PathBuf::from(&config.directory.0).join("index.md"),
+                        vec![],
+                    );
+                    index_chapter.sub_items =
+                        post_chapters.into_iter().map(BookItem::Chapter).collect();
+                    book.push_item(index_chapter);
+                },
+            }
+        }
+
+        if let Some(tags_overview) = tags_overview {
+            book.push_item(tags_overview);
+        }
+
         Ok(book)
     }
 
@@ -323,4 +981,334 @@ mod test {
         let actual_book = result.unwrap();
         assert_eq!(actual_book, expected_book);
     }
+
+    fn post_dated(date: Date) -> Post {
+        Post::new(
+            PathBuf::from("2024-01-01-my-post.md"),
+            date,
+            "my-post".to_string(),
+            "Posts".to_string(),
+            "Some content.".to_string(),
+            FrontMatter::default(),
+        )
+    }
+
+    #[test]
+    fn filter_future_posts_drops_future_posts_by_default() {
+        let today: Date = "2024-01-01".parse().unwrap();
+        let tomorrow: Date = "2024-01-02".parse().unwrap();
+
+        let posts = vec![post_dated(today), post_dated(tomorrow)];
+        let posts = filter_future_posts(posts, false, today);
+
+        assert_eq!(posts.len(), 1);
+        assert_eq!(posts[0].date, today);
+    }
+
+    #[test]
+    fn filter_future_posts_keeps_future_posts_when_enabled() {
+        let today: Date = "2024-01-01".parse().unwrap();
+        let tomorrow: Date = "2024-01-02".parse().unwrap();
+
+        let posts = vec![post_dated(today), post_dated(tomorrow)];
+        let posts = filter_future_posts(posts, true, today);
+
+        assert_eq!(posts.len(), 2);
+    }
+
+    #[test]
+    fn inject_scheduled_notice_prepends_a_notice() {
+        let tomorrow: Date = "2024-01-02".parse().unwrap();
+        let content = inject_scheduled_notice("Some content.", tomorrow);
+
+        assert!(content.starts_with("> **Scheduled:**"));
+        assert!(content.ends_with("Some content."));
+    }
+
+    fn make_post(date: Date, slug: &str, title: Option<&str>) -> Post {
+        let mut front_matter = FrontMatter::default();
+        front_matter.title = title.map(str::to_string);
+        Post::new(
+            PathBuf::from(format!("2024-01-01-{slug}.md")),
+            date,
+            slug.to_string(),
+            "Posts".to_string(),
+            "Some content.".to_string(),
+            front_matter,
+        )
+    }
+
+    fn sort_by_slugs(mut posts: Vec<Post>, sort_by: SortBy) -> Vec<String> {
+        sort_posts(&mut posts, &sort_by);
+        posts.into_iter().map(|post| post.name).collect()
+    }
+
+    #[test]
+    fn sort_by_newest_orders_descending_by_date() {
+        let d1: Date = "2024-01-01".parse().unwrap();
+        let d2: Date = "2024-02-01".parse().unwrap();
+        let posts = vec![make_post(d1, "a", None), make_post(d2, "b", None)];
+
+        assert_eq!(sort_by_slugs(posts, SortBy::Newest), vec!["b", "a"]);
+    }
+
+    #[test]
+    fn sort_by_oldest_orders_ascending_by_date() {
+        let d1: Date = "2024-01-01".parse().unwrap();
+        let d2: Date = "2024-02-01".parse().unwrap();
+        let posts = vec![make_post(d2, "b", None), make_post(d1, "a", None)];
+
+        assert_eq!(sort_by_slugs(posts, SortBy::Oldest), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn sort_by_name_uses_front_matter_title_over_slug() {
+        let date: Date = "2024-01-01".parse().unwrap();
+        let posts = vec![
+            make_post(date, "z-slug", Some("Apple")),
+            make_post(date, "a-slug", Some("Banana")),
+        ];
+
+        assert_eq!(sort_by_slugs(posts, SortBy::NameAZ), vec!["z-slug", "a-slug"]);
+    }
+
+    #[test]
+    fn sort_by_name_za_reverses_order() {
+        let date: Date = "2024-01-01".parse().unwrap();
+        let posts = vec![
+            make_post(date, "a-slug", Some("Apple")),
+            make_post(date, "b-slug", Some("Banana")),
+        ];
+
+        assert_eq!(sort_by_slugs(posts, SortBy::NameZA), vec!["b-slug", "a-slug"]);
+    }
+
+    #[test]
+    fn sort_by_newest_breaks_ties_by_slug() {
+        let date: Date = "2024-01-01".parse().unwrap();
+        let posts = vec![make_post(date, "b", None), make_post(date, "a", None)];
+
+        assert_eq!(sort_by_slugs(posts, SortBy::Newest), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn de_slugify_capitalizes_each_word() {
+        assert_eq!(de_slugify("my-super-post"), "My Super Post");
+    }
+
+    #[test]
+    fn split_front_matter_extracts_yaml_block() {
+        let content = "---\ntitle: Test\n---\nBody\n";
+        let (block, body) = split_front_matter(content, "---").unwrap();
+
+        assert!(block.contains("title: Test"));
+        assert_eq!(body, "Body\n");
+    }
+
+    #[test]
+    fn split_front_matter_returns_none_without_opening_delimiter() {
+        assert!(split_front_matter("Just a post.", "---").is_none());
+    }
+
+    #[test]
+    fn split_front_matter_returns_none_without_a_matching_closing_delimiter() {
+        let content = "---\ntitle: Test\nno closing fence here\n";
+        assert!(split_front_matter(content, "---").is_none());
+    }
+
+    #[test]
+    fn split_front_matter_ignores_the_delimiter_mid_line() {
+        let content = "---\ntitle: well-known---ish fact\n---\nBody\n";
+        let (block, body) = split_front_matter(content, "---").unwrap();
+
+        assert!(block.contains("well-known---ish fact"));
+        assert_eq!(body, "Body\n");
+    }
+
+    #[test]
+    fn parse_front_matter_parses_yaml() {
+        let content = "---\ntitle: Test\ntags: [a, b]\n---\nBody\n";
+        let (front_matter, body) = parse_front_matter(content).unwrap();
+
+        assert_eq!(front_matter.title.as_deref(), Some("Test"));
+        assert_eq!(front_matter.tags, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(body, "Body\n");
+    }
+
+    #[test]
+    fn parse_front_matter_parses_toml() {
+        let content = "+++\ntitle = \"Test\"\n+++\nBody\n";
+        let (front_matter, body) = parse_front_matter(content).unwrap();
+
+        assert_eq!(front_matter.title.as_deref(), Some("Test"));
+        assert_eq!(body, "Body\n");
+    }
+
+    #[test]
+    fn parse_front_matter_falls_back_to_defaults_without_a_front_matter_block() {
+        let content = "Just a post.\n";
+        let (front_matter, body) = parse_front_matter(content).unwrap();
+
+        assert_eq!(front_matter.title, None);
+        assert_eq!(body, content);
+    }
+
+    #[test]
+    fn slugify_lowercases_and_replaces_non_alphanumeric_characters() {
+        assert_eq!(slugify("  Rust & WASM!  "), "rust---wasm-");
+    }
+
+    #[test]
+    fn build_tags_overview_groups_posts_by_tag() {
+        let post_chapters = vec![
+            Chapter::new("Post A", String::new(), PathBuf::from("posts/a.md"), vec![]),
+            Chapter::new("Post B", String::new(), PathBuf::from("posts/b.md"), vec![]),
+        ];
+        let post_tags = vec![
+            vec!["rust".to_string()],
+            vec!["rust".to_string(), "wasm".to_string()],
+        ];
+
+        let overview = build_tags_overview(&post_chapters, &post_tags, "Tags");
+
+        assert_eq!(overview.name, "Tags");
+        assert!(overview.content.contains("[rust]"));
+        assert!(overview.content.contains("[wasm]"));
+        assert_eq!(overview.sub_items.len(), 2);
+
+        let rust_tag = overview
+            .sub_items
+            .iter()
+            .find_map(|item| match item {
+                BookItem::Chapter(chapter) if chapter.name == "rust" => Some(chapter),
+                _ => None,
+            })
+            .unwrap();
+        assert!(rust_tag.content.contains("Post A"));
+        assert!(rust_tag.content.contains("Post B"));
+    }
+
+    /// Creates a fresh, empty directory under the system temp dir for a test
+    /// to write fixture posts into. Keyed on the process id so that separate
+    /// runs of the test binary do not share it, and wiped before use.
+    fn temp_posts_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("mdbook-blog-test-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn collect_localized_posts_prefers_localized_over_fallback() {
+        let posts_dir = temp_posts_dir("localized-prefers");
+        std::fs::create_dir_all(posts_dir.join("fr")).unwrap();
+
+        std::fs::write(posts_dir.join("2024-01-01-hello.md"), "Fallback content").unwrap();
+        std::fs::write(
+            posts_dir.join("fr").join("2024-01-01-hello.md"),
+            "Contenu localisé",
+        )
+        .unwrap();
+
+        let posts = collect_localized_posts(&posts_dir, "fr", "Posts".to_string(), false);
+
+        assert_eq!(posts.len(), 1);
+        assert_eq!(posts[0].content, "Contenu localisé");
+
+        std::fs::remove_dir_all(&posts_dir).ok();
+    }
+
+    #[test]
+    fn collect_localized_posts_falls_back_when_untranslated() {
+        let posts_dir = temp_posts_dir("localized-fallback");
+        std::fs::create_dir_all(posts_dir.join("fr")).unwrap();
+
+        std::fs::write(posts_dir.join("2024-01-02-only-fallback.md"), "English only").unwrap();
+
+        let posts = collect_localized_posts(&posts_dir, "fr", "Posts".to_string(), false);
+
+        assert_eq!(posts.len(), 1);
+        assert_eq!(posts[0].content, "English only");
+
+        std::fs::remove_dir_all(&posts_dir).ok();
+    }
+
+    #[test]
+    fn collect_posts_recurses_into_subdirectories_when_unbounded() {
+        let posts_dir = temp_posts_dir("nested-posts");
+        std::fs::create_dir_all(posts_dir.join("2024")).unwrap();
+        std::fs::write(
+            posts_dir.join("2024").join("2024-01-01-nested.md"),
+            "Nested content",
+        )
+        .unwrap();
+
+        let posts = collect_posts(WalkDir::new(&posts_dir), "Posts".to_string(), false);
+        assert_eq!(posts.len(), 1);
+        assert_eq!(posts[0].content, "Nested content");
+
+        std::fs::remove_dir_all(&posts_dir).ok();
+    }
+
+    fn feed_post(date: Date, slug: &str) -> Post {
+        Post::new(
+            PathBuf::from(format!("/book/src/posts/2024-01-01-{slug}.md")),
+            date,
+            slug.to_string(),
+            "Posts".to_string(),
+            "Some content.".to_string(),
+            FrontMatter::default(),
+        )
+    }
+
+    #[test]
+    fn build_feed_entries_orders_by_date_descending_and_respects_count() {
+        let src_dir = PathBuf::from("/book/src");
+        let posts = vec![
+            feed_post("2024-01-01".parse().unwrap(), "old"),
+            feed_post("2024-03-01".parse().unwrap(), "new"),
+            feed_post("2024-02-01".parse().unwrap(), "mid"),
+        ];
+
+        let entries = build_feed_entries(&posts, &src_dir, "https://example.com/", 2);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].title, "New");
+        assert_eq!(entries[0].link, "https://example.com/posts/2024-01-01-new.html");
+        assert_eq!(entries[1].title, "Mid");
+    }
+
+    #[test]
+    fn escape_xml_escapes_reserved_characters() {
+        assert_eq!(
+            escape_xml("<a> & \"b\" 'c'"),
+            "&lt;a&gt; &amp; &quot;b&quot; &apos;c&apos;"
+        );
+    }
+
+    fn feed_entry(date: Date) -> FeedEntry {
+        FeedEntry {
+            title: "Hello".to_string(),
+            link: "https://example.com/hello.html".to_string(),
+            date,
+            excerpt: "An excerpt".to_string(),
+        }
+    }
+
+    #[test]
+    fn render_atom_feed_formats_timestamps_as_rfc3339() {
+        let entries = vec![feed_entry("2024-01-02".parse().unwrap())];
+        let xml = render_atom_feed(&entries, "https://example.com");
+
+        assert!(xml.contains("<title>Hello</title>"));
+        assert!(xml.contains("<updated>2024-01-02T00:00:00Z</updated>"));
+    }
+
+    #[test]
+    fn render_rss_feed_formats_pub_date_as_rfc822() {
+        let entries = vec![feed_entry("2024-01-02".parse().unwrap())];
+        let xml = render_rss_feed(&entries, "https://example.com");
+
+        assert!(xml.contains("<pubDate>Tue, 02 Jan 2024 00:00:00 GMT</pubDate>"));
+    }
 }